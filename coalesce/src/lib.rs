@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::future::{BoxFuture, FutureExt, Shared};
+use parking_lot::Mutex;
+
+/// A single-flight, TTL-cached async lookup group keyed by `K`.
+///
+/// The first caller for a given key becomes the leader: it runs the
+/// supplied future and every other caller for the same key ("followers")
+/// clones and awaits that *same* `Shared` future instead of each issuing its
+/// own request. `Shared` retains its output once the inner future resolves,
+/// so a follower that only starts polling after the leader has already
+/// finished still gets the value — unlike a one-shot channel, there's no
+/// window where a late subscriber misses the result and hangs.
+pub struct Coalescer<K, V> {
+    inflight: Arc<Mutex<HashMap<K, Shared<BoxFuture<'static, V>>>>>,
+    cache: Arc<Mutex<HashMap<K, (V, Instant)>>>,
+    ttl: Duration,
+}
+
+impl<K, V> Coalescer<K, V>
+where
+    K: Eq + Hash + Clone + Send + 'static,
+    V: Clone + Send + 'static,
+{
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            inflight: Arc::new(Mutex::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            ttl,
+        }
+    }
+
+    /// Resolves `key`, running `fetch` at most once per group of concurrent
+    /// callers and reusing a fresh cached value when one is available.
+    pub async fn get_with<F, Fut>(&self, key: K, fetch: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V> + Send + 'static,
+    {
+        if let Some(value) = self.cached(&key) {
+            return value;
+        }
+
+        let shared = {
+            let mut inflight = self.inflight.lock();
+            match inflight.get(&key) {
+                Some(existing) => existing.clone(),
+                None => {
+                    let shared = self.leader_future(key.clone(), fetch).shared();
+                    inflight.insert(key, shared.clone());
+                    shared
+                }
+            }
+        };
+
+        shared.await
+    }
+
+    /// Builds the leader future: runs `fetch`, populates the TTL cache, and
+    /// clears the in-flight entry — all exactly once, since `Shared` only
+    /// polls the inner future a single time no matter how many callers
+    /// clone it.
+    ///
+    /// This is a boxed future, not a spawned task: it only makes progress
+    /// while some caller is polling the `Shared` wrapping it (i.e. while at
+    /// least one `get_with` call is awaiting it). If the original caller is
+    /// dropped with no follower currently polling, the fetch stalls until
+    /// the next caller for this key comes along.
+    fn leader_future<F, Fut>(&self, key: K, fetch: F) -> BoxFuture<'static, V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V> + Send + 'static,
+    {
+        let cache = self.cache.clone();
+        let inflight = self.inflight.clone();
+        let fetch = fetch();
+
+        async move {
+            let value = fetch.await;
+            cache.lock().insert(key.clone(), (value.clone(), Instant::now()));
+            inflight.lock().remove(&key);
+            value
+        }
+        .boxed()
+    }
+
+    fn cached(&self, key: &K) -> Option<V> {
+        let cache = self.cache.lock();
+        cache.get(key).and_then(|(value, at)| {
+            if at.elapsed() < self.ttl {
+                Some(value.clone())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration as StdDuration;
+
+    #[tokio::test]
+    async fn concurrent_callers_for_the_same_key_share_one_fetch() {
+        let coalescer = Arc::new(Coalescer::<String, u32>::new(StdDuration::from_secs(60)));
+        let fetch_count = Arc::new(AtomicUsize::new(0));
+
+        let mut tasks = Vec::new();
+        for _ in 0..32 {
+            let coalescer = coalescer.clone();
+            let fetch_count = fetch_count.clone();
+            tasks.push(tokio::spawn(async move {
+                coalescer
+                    .get_with("key".to_string(), || {
+                        let fetch_count = fetch_count.clone();
+                        async move {
+                            fetch_count.fetch_add(1, Ordering::SeqCst);
+                            // Give other callers a chance to join as
+                            // followers before the leader resolves.
+                            tokio::task::yield_now().await;
+                            42
+                        }
+                    })
+                    .await
+            }));
+        }
+
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), 42);
+        }
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn late_follower_still_gets_the_value_after_the_leader_finishes() {
+        let coalescer = Arc::new(Coalescer::<String, u32>::new(StdDuration::from_secs(60)));
+
+        // Leader runs to completion (and clears the in-flight entry) before
+        // the follower ever calls `get_with`, so the follower is served from
+        // the TTL cache the leader populated: it gets the leader's value
+        // promptly instead of hanging, and its own fetch never runs.
+        let leader = coalescer.clone();
+        leader
+            .get_with("key".to_string(), || async { 7 })
+            .await;
+
+        let value = tokio::time::timeout(
+            StdDuration::from_secs(1),
+            coalescer.get_with("key".to_string(), || async { 99 }),
+        )
+        .await
+        .expect("follower must not hang");
+
+        assert_eq!(value, 7);
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use coalesce::Coalescer;
+use lazy_static::lazy_static;
+use tokio_util::sync::CancellationToken;
+
+lazy_static! {
+    static ref CONFIG_CACHE: Coalescer<String, String> = Coalescer::new(Duration::from_secs(30));
+}
+
+/// Returns a handle the shutdown coordinator can use to wait for this
+/// module to drain. `confman` has no persistent background task today, so
+/// the handle's task simply completes once `token` is cancelled.
+pub fn init(token: CancellationToken) -> shutdown::ModuleHandle {
+    tracing::info!("[confman] module initialized");
+
+    let task = tokio::spawn(async move {
+        token.cancelled().await;
+        tracing::info!("[confman] module drained");
+    });
+    shutdown::ModuleHandle::new("confman", task)
+}
+
+/// Fetches a configuration value by key. Concurrent lookups for the same
+/// key are coalesced onto a single backend read, and results are cached for
+/// a short TTL so repeated startup/`ConfigReloaded` reads don't hammer the
+/// backend.
+pub async fn get(key: &str) -> String {
+    let cache_key = key.to_string();
+    let fetch_key = cache_key.clone();
+    CONFIG_CACHE
+        .get_with(cache_key, move || async move { fetch_from_backend(&fetch_key).await })
+        .await
+}
+
+async fn fetch_from_backend(key: &str) -> String {
+    tracing::debug!(key, "[confman] backend lookup");
+    String::new()
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use coalesce::Coalescer;
+use lazy_static::lazy_static;
+use tokio_util::sync::CancellationToken;
+
+lazy_static! {
+    static ref REFDATA_CACHE: Coalescer<String, serde_json::Value> = Coalescer::new(Duration::from_secs(60));
+}
+
+/// Returns a handle the shutdown coordinator can use to wait for this
+/// module to drain. `db` has no persistent background task today, so the
+/// handle's task simply completes once `token` is cancelled.
+pub fn init(token: CancellationToken) -> shutdown::ModuleHandle {
+    tracing::info!("[db] module initialized");
+
+    let task = tokio::spawn(async move {
+        token.cancelled().await;
+        tracing::info!("[db] module drained");
+    });
+    shutdown::ModuleHandle::new("db", task)
+}
+
+/// Fetches reference data (symbol metadata, market calendars, ...) by key.
+/// Concurrent lookups for the same key are coalesced onto a single backend
+/// read, and results are cached for a short TTL — the standard read path
+/// for data that's likely requested by many tasks at once on startup and on
+/// `ConfigReloaded`.
+pub async fn get_reference_data(key: &str) -> serde_json::Value {
+    let cache_key = key.to_string();
+    let fetch_key = cache_key.clone();
+    REFDATA_CACHE
+        .get_with(cache_key, move || async move { fetch_reference_data(&fetch_key).await })
+        .await
+}
+
+async fn fetch_reference_data(key: &str) -> serde_json::Value {
+    tracing::debug!(key, "[db] reference data lookup");
+    serde_json::Value::Null
+}
@@ -1,5 +1,10 @@
-use tokio::sync::broadcast;
-use sysbus::SysEvent;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sysbus::{Bus, SysEvent};
+use tracing_subscriber::{layer::SubscriberExt, reload, util::SubscriberInitExt, EnvFilter, Registry};
+
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub fn bootstrap() {
     tracing::info!("FinStream bootstrap sequence started");
@@ -7,25 +12,43 @@ pub fn bootstrap() {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tracing_subscriber::fmt()
-        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-        .json()
+    let (filter_layer, reload_handle) = reload::Layer::new(EnvFilter::from_default_env());
+    logger::reload::set_reload_handle(reload_handle);
+
+    Registry::default()
+        .with(filter_layer)
+        .with(tracing_subscriber::fmt::layer().json())
         .init();
 
     tracing::info!("FinStream Engine Core Starting...");
 
-    let (tx, _rx) = broadcast::channel::<SysEvent>(1024);
-    let _bus_tx = tx;
+    let mut bus = Bus::new(1024);
+    if let Ok(redis_url) = std::env::var("FINSTREAM_REDIS_BUS_URL") {
+        match sysbus::transport::redis::RedisTransport::connect(&redis_url).await {
+            Ok(remote) => bus = bus.with_remote(Arc::new(remote)),
+            Err(err) => tracing::warn!(%err, "[sysbus] failed to connect remote bus transport, staying local-only"),
+        }
+    }
+    let bus = bus;
 
     bootstrap();
 
-    logger::init();
-    confman::init();
-    db::init();
-    servman::init();
+    let mut shutdown = shutdown::ShutdownCoordinator::new();
+    let token = shutdown.token();
+
+    shutdown.register(logger::init(&bus, token.clone()));
+    shutdown.register(confman::init(token.clone()));
+    shutdown.register(db::init(token.clone()));
+
+    let sse_addr: std::net::SocketAddr = std::env::var("FINSTREAM_SSE_ADDR")
+        .ok()
+        .and_then(|addr| addr.parse().ok())
+        .unwrap_or_else(|| ([127, 0, 0, 1], 8090).into());
+    shutdown.register(servman::init(&bus, sse_addr, token.clone()));
+
     climan::init();
     assman::init();
-    telemetry::init();
+    shutdown.register(telemetry::init(&bus, token.clone()));
     marketstatus::init();
     primon::init();
     sysbus::init();
@@ -35,5 +58,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tokio::signal::ctrl_c().await?;
     tracing::info!("Shutting down FinStream...");
 
+    bus.publish(SysEvent::Shutdown);
+    shutdown.shutdown(SHUTDOWN_TIMEOUT).await;
+
+    tracing::info!("FinStream shut down cleanly");
+
     Ok(())
 }
@@ -0,0 +1,54 @@
+use sysbus::SysEvent;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+use crate::reload;
+
+/// Listens on the system bus for config/log-filter control events and
+/// applies them through the stored tracing reload handle.
+///
+/// This is the runtime control surface for log verbosity: an operator (or
+/// an automated process) can publish `SysEvent::SetLogFilter("db=debug,servman=trace")`
+/// onto the bus to change per-module verbosity without restarting the
+/// engine, and `SysEvent::ConfigReloaded` re-reads `RUST_LOG` from the
+/// environment for the common "I edited the env and want it picked up" case.
+///
+/// Exits cleanly as soon as `token` is cancelled or `SysEvent::Shutdown` is
+/// observed, whichever comes first.
+pub async fn run(mut rx: broadcast::Receiver<SysEvent>, token: CancellationToken) {
+    loop {
+        let event = tokio::select! {
+            biased;
+            _ = token.cancelled() => break,
+            event = rx.recv() => event,
+        };
+
+        match event {
+            Ok(SysEvent::ConfigReloaded) => {
+                let directives = default_directives();
+                match reload::apply_filter(&directives) {
+                    Ok(()) => tracing::info!(directives = %directives, "[logger] reloaded EnvFilter from environment"),
+                    Err(err) => tracing::warn!(%err, "[logger] failed to reload EnvFilter from environment"),
+                }
+            }
+            Ok(SysEvent::SetLogFilter(directives)) => {
+                match reload::apply_filter(&directives) {
+                    Ok(()) => tracing::info!(%directives, "[logger] applied new EnvFilter"),
+                    Err(err) => tracing::warn!(%err, %directives, "[logger] failed to apply EnvFilter"),
+                }
+            }
+            Ok(SysEvent::Shutdown) => break,
+            Ok(_) => {}
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!(skipped, "[logger] control listener lagged behind the bus");
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+
+    tracing::info!("[logger] control listener drained");
+}
+
+fn default_directives() -> String {
+    std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string())
+}
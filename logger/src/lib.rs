@@ -0,0 +1,14 @@
+use sysbus::Bus;
+use tokio_util::sync::CancellationToken;
+
+pub mod control;
+pub mod reload;
+
+/// Starts the log-filter control listener and returns a handle the shutdown
+/// coordinator can use to wait for it to drain.
+pub fn init(bus: &Bus, token: CancellationToken) -> shutdown::ModuleHandle {
+    tracing::info!("[logger] module initialized");
+
+    let task = tokio::spawn(control::run(bus.subscribe(), token));
+    shutdown::ModuleHandle::new("logger", task)
+}
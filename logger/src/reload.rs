@@ -1,8 +1,12 @@
 use parking_lot::RwLock;
 use std::sync::Arc;
-use tracing_subscriber::{EnvFilter, reload};
+use tracing_subscriber::{reload, EnvFilter, Registry};
 
-pub type ReloadHandle = reload::Handle<EnvFilter, Arc<RwLock<EnvFilter>>>;
+/// `Handle`'s second type parameter is the subscriber the reloadable layer
+/// is composed onto (here, the `Registry` built in `main`), not the
+/// filter's own interior-mutability wrapper — `reload::Layer::new` is only
+/// generic over the subscriber it'll end up layered on.
+pub type ReloadHandle = reload::Handle<EnvFilter, Registry>;
 
 lazy_static::lazy_static! {
     pub static ref RELOAD_HANDLE: Arc<RwLock<Option<ReloadHandle>>> = Arc::new(RwLock::new(None));
@@ -11,3 +15,17 @@ lazy_static::lazy_static! {
 pub fn set_reload_handle(handle: ReloadHandle) {
     *RELOAD_HANDLE.write() = Some(handle);
 }
+
+/// Parses `directives` as an `EnvFilter` and swaps it into the live
+/// subscriber via the stored reload handle.
+///
+/// Returns an error if the directives fail to parse, or if no handle has
+/// been registered yet (i.e. called before the tracing subscriber was
+/// installed).
+pub fn apply_filter(directives: &str) -> Result<(), String> {
+    let new_filter = EnvFilter::try_new(directives).map_err(|e| e.to_string())?;
+    match RELOAD_HANDLE.read().as_ref() {
+        Some(handle) => handle.modify(|filter| *filter = new_filter).map_err(|e| e.to_string()),
+        None => Err("reload handle not yet initialized".to_string()),
+    }
+}
@@ -0,0 +1,27 @@
+//! Operator control surface: a small HTTP route that publishes control
+//! events onto the bus directly, so changing log verbosity works even when
+//! no remote bus transport (e.g. Redis) is configured — `sysbus::Bus`'s
+//! remote ingress (see `Bus::with_remote`) covers the Redis case, this
+//! covers the local/no-Redis one.
+
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+use serde::Deserialize;
+use sysbus::SysEvent;
+
+use crate::sse::SseState;
+
+#[derive(Debug, Deserialize)]
+struct SetLogFilterRequest {
+    directives: String,
+}
+
+pub(crate) fn routes() -> Router<SseState> {
+    Router::new().route("/control/log-filter", post(set_log_filter))
+}
+
+/// `POST /control/log-filter` with `{"directives": "db=debug,servman=trace"}`
+/// publishes `SysEvent::SetLogFilter`, picked up by `logger::control::run`.
+async fn set_log_filter(State(state): State<SseState>, Json(body): Json<SetLogFilterRequest>) -> StatusCode {
+    state.bus.publish(SysEvent::SetLogFilter(body.directives));
+    StatusCode::ACCEPTED
+}
@@ -0,0 +1,21 @@
+use std::net::SocketAddr;
+
+use sysbus::Bus;
+use tokio_util::sync::CancellationToken;
+
+mod control;
+pub mod sse;
+
+/// Starts the SSE endpoint on `addr` and returns a handle the shutdown
+/// coordinator can use to wait for it to drain.
+pub fn init(bus: &Bus, addr: SocketAddr, token: CancellationToken) -> shutdown::ModuleHandle {
+    tracing::info!("[servman] module initialized");
+
+    let bus = bus.clone();
+    let task = tokio::spawn(async move {
+        if let Err(err) = sse::serve(bus, addr, token).await {
+            tracing::error!(%err, "[servman] SSE endpoint exited with an error");
+        }
+    });
+    shutdown::ModuleHandle::new("servman", task)
+}
@@ -0,0 +1,119 @@
+//! Server-Sent Events bridge: re-emits `SysEvent`s from the bus as an SSE
+//! stream for external dashboards/browser clients that can't speak the
+//! internal broadcast protocol.
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    response::sse::{Event, KeepAlive, Sse},
+    routing::get,
+    Router,
+};
+use futures::stream::{Stream, StreamExt};
+use serde::Deserialize;
+use sysbus::{topic_of, Bus, SysEvent};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Clone)]
+pub(crate) struct SseState {
+    pub(crate) bus: Bus,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventFilter {
+    /// Comma-separated list of event topics to include, e.g.
+    /// `?type=finstream.market_status,finstream.telemetry_tick`. Omit to
+    /// receive every event.
+    #[serde(rename = "type")]
+    types: Option<String>,
+}
+
+/// Serves `GET /events` on `addr`, streaming every `SysEvent` published on
+/// `bus` as `text/event-stream`. Shuts down gracefully once `token` is
+/// cancelled.
+pub async fn serve(bus: Bus, addr: SocketAddr, token: CancellationToken) -> std::io::Result<()> {
+    let app = Router::new()
+        .route("/events", get(events_handler))
+        .merge(crate::control::routes())
+        .with_state(SseState { bus });
+
+    tracing::info!(%addr, "[servman] SSE endpoint listening");
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move { token.cancelled().await })
+        .await
+}
+
+async fn events_handler(
+    State(state): State<SseState>,
+    Query(filter): Query<EventFilter>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let wanted: Option<Vec<String>> = filter
+        .types
+        .map(|s| s.split(',').map(str::trim).map(str::to_string).collect());
+
+    let rx = state.bus.subscribe();
+    let stream = BroadcastStream::new(rx).filter_map(move |item| {
+        let wanted = wanted.clone();
+        async move {
+            match item {
+                Ok(event) => sse_event_for(&event, wanted.as_deref()),
+                // A lagging client gets a one-off notice instead of a stalled
+                // producer: the broadcast channel itself never blocks on SSE
+                // backpressure, we just tell the client it missed some.
+                Err(broadcast::error::RecvError::Lagged(skipped)) => Some(Ok(Event::default()
+                    .event("lagged")
+                    .data(skipped.to_string()))),
+                Err(broadcast::error::RecvError::Closed) => None,
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::new().interval(Duration::from_secs(15)))
+}
+
+fn sse_event_for(event: &SysEvent, wanted: Option<&[String]>) -> Option<Result<Event, Infallible>> {
+    let topic = topic_of(event);
+    if let Some(wanted) = wanted {
+        if !wanted.iter().any(|t| t == topic) {
+            return None;
+        }
+    }
+
+    let data = serde_json::to_string(event).ok()?;
+    Some(Ok(Event::default().event(topic).data(data)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn includes_every_event_when_no_filter_is_given() {
+        let event = SysEvent::MarketStatus("open".to_string());
+        assert!(sse_event_for(&event, None).is_some());
+    }
+
+    #[test]
+    fn includes_only_requested_topics() {
+        let event = SysEvent::MarketStatus("open".to_string());
+        let wanted = ["finstream.market_status".to_string()];
+
+        let frame = sse_event_for(&event, Some(&wanted)).expect("topic is in the allow-list");
+        let rendered = format!("{:?}", frame.expect("infallible"));
+        assert!(rendered.contains("finstream.market_status"));
+    }
+
+    #[test]
+    fn excludes_topics_not_in_the_filter() {
+        let event = SysEvent::MarketStatus("open".to_string());
+        let wanted = ["finstream.telemetry_tick".to_string()];
+
+        assert!(sse_event_for(&event, Some(&wanted)).is_none());
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+/// Handle returned by a module's `init()` once it's wired into graceful
+/// shutdown: `task` is the module's background work, which is expected to
+/// observe the `CancellationToken` it was given and return once it has
+/// drained.
+pub struct ModuleHandle {
+    pub name: &'static str,
+    pub task: tokio::task::JoinHandle<()>,
+}
+
+impl ModuleHandle {
+    pub fn new(name: &'static str, task: tokio::task::JoinHandle<()>) -> Self {
+        Self { name, task }
+    }
+}
+
+/// Coordinates cooperative shutdown across every module: cancels the shared
+/// token, then waits (up to a timeout) for every registered module task to
+/// finish draining before the process exits.
+pub struct ShutdownCoordinator {
+    token: CancellationToken,
+    handles: Vec<ModuleHandle>,
+}
+
+impl ShutdownCoordinator {
+    pub fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+            handles: Vec::new(),
+        }
+    }
+
+    /// A clone of the shared token, to be passed into each module's
+    /// `init()` so it can observe cancellation.
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    pub fn register(&mut self, handle: ModuleHandle) {
+        self.handles.push(handle);
+    }
+
+    /// Cancels the token and waits up to `timeout` for every registered
+    /// module task to finish; logs and gives up if the timeout elapses.
+    ///
+    /// Modules drain concurrently under one shared timeout, not one after
+    /// another — otherwise a module that ignores the token would starve
+    /// every module registered after it of any chance to drain, and
+    /// well-behaved modules' drain times would sum instead of overlap.
+    pub async fn shutdown(self, timeout: Duration) {
+        self.token.cancel();
+
+        let drain_all = futures::future::join_all(self.handles.into_iter().map(|handle| async move {
+            let name = handle.name;
+            if let Err(err) = handle.task.await {
+                tracing::warn!(module = name, %err, "[shutdown] module task panicked during drain");
+            }
+        }));
+
+        if tokio::time::timeout(timeout, drain_all).await.is_err() {
+            tracing::warn!("[shutdown] timed out waiting for modules to drain, exiting anyway");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn shutdown_cancels_the_token_and_waits_for_well_behaved_modules() {
+        let mut coordinator = ShutdownCoordinator::new();
+        let token = coordinator.token();
+        let drained = Arc::new(AtomicBool::new(false));
+
+        let task = {
+            let token = token.clone();
+            let drained = drained.clone();
+            tokio::spawn(async move {
+                token.cancelled().await;
+                drained.store(true, Ordering::SeqCst);
+            })
+        };
+        coordinator.register(ModuleHandle::new("well-behaved", task));
+
+        coordinator.shutdown(Duration::from_secs(5)).await;
+
+        assert!(token.is_cancelled());
+        assert!(drained.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn shutdown_gives_up_after_the_timeout_instead_of_hanging_forever() {
+        let mut coordinator = ShutdownCoordinator::new();
+
+        // A module that ignores the cancellation token entirely — the
+        // coordinator must not wait for it past `timeout`.
+        let task = tokio::spawn(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+        coordinator.register(ModuleHandle::new("misbehaving", task));
+
+        let started = Instant::now();
+        coordinator.shutdown(Duration::from_millis(100)).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5), "shutdown should return promptly once the timeout elapses");
+    }
+
+    #[tokio::test]
+    async fn a_misbehaving_module_does_not_starve_a_well_behaved_module_registered_after_it() {
+        let mut coordinator = ShutdownCoordinator::new();
+        let token = coordinator.token();
+        let drained = Arc::new(AtomicBool::new(false));
+
+        // Registered first, but ignores the token — under sequential
+        // draining this would starve the well-behaved module below for the
+        // entire timeout.
+        coordinator.register(ModuleHandle::new(
+            "misbehaving",
+            tokio::spawn(async { tokio::time::sleep(Duration::from_secs(60)).await }),
+        ));
+
+        let task = {
+            let token = token.clone();
+            let drained = drained.clone();
+            tokio::spawn(async move {
+                token.cancelled().await;
+                drained.store(true, Ordering::SeqCst);
+            })
+        };
+        coordinator.register(ModuleHandle::new("well-behaved", task));
+
+        coordinator.shutdown(Duration::from_millis(200)).await;
+
+        assert!(drained.load(Ordering::SeqCst), "well-behaved module should drain despite a stuck sibling");
+    }
+}
@@ -0,0 +1,189 @@
+//! Compact binary wire format for off-process bus traffic (remote
+//! transport, persisted replay log). JSON is wasteful for high-frequency
+//! `TelemetryTick`/`MarketStatus` traffic; this wraps a `bincode` payload in
+//! a small envelope so consumers can reject or upgrade incompatible
+//! messages instead of failing to parse silently. Serde-JSON stays
+//! available per-transport as a debug/interop format.
+
+use serde::{Deserialize, Serialize};
+
+use crate::SysEvent;
+
+/// Bumped whenever `SysEvent`'s shape changes in a way that breaks binary
+/// compatibility with already-deployed consumers.
+pub const SCHEMA_VERSION: u16 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CodecError {
+    #[error("unsupported schema version {0}, expected {SCHEMA_VERSION}")]
+    UnsupportedSchemaVersion(u16),
+    #[error("bincode encode error: {0}")]
+    Encode(#[from] bincode::error::EncodeError),
+    #[error("bincode decode error: {0}")]
+    Decode(#[from] bincode::error::DecodeError),
+    #[error("json codec error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Wire format a transport encodes/decodes `SysEvent`s with. `Bincode` is
+/// the default for high-frequency traffic; `Json` trades size for being
+/// human-readable, useful when debugging a transport or bridging to a
+/// consumer that only speaks JSON (e.g. the SSE endpoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WireFormat {
+    #[default]
+    Bincode,
+    Json,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    schema_version: u16,
+    variant_tag: u8,
+    payload: Vec<u8>,
+}
+
+/// Stable per-variant tag carried in the envelope so a consumer can route or
+/// reject a message by variant without decoding the inner payload. Order
+/// matters for wire compatibility: append new variants at the end, never
+/// renumber or reuse a retired tag.
+fn variant_tag(event: &SysEvent) -> u8 {
+    match event {
+        SysEvent::ConfigReloaded => 0,
+        SysEvent::MarketStatus(_) => 1,
+        SysEvent::AssetTransition { .. } => 2,
+        SysEvent::TelemetryTick(_) => 3,
+        SysEvent::SetLogFilter(_) => 4,
+        SysEvent::Shutdown => 5,
+    }
+}
+
+fn decode_envelope(bytes: &[u8]) -> Result<Envelope, CodecError> {
+    let (envelope, _): (Envelope, usize) =
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard())?;
+    if envelope.schema_version != SCHEMA_VERSION {
+        return Err(CodecError::UnsupportedSchemaVersion(envelope.schema_version));
+    }
+    Ok(envelope)
+}
+
+impl SysEvent {
+    /// Encodes this event as a versioned bincode envelope for off-process
+    /// transport.
+    pub fn encode(&self) -> Result<Vec<u8>, CodecError> {
+        let payload = bincode::serde::encode_to_vec(self, bincode::config::standard())?;
+        let envelope = Envelope {
+            schema_version: SCHEMA_VERSION,
+            variant_tag: variant_tag(self),
+            payload,
+        };
+        Ok(bincode::serde::encode_to_vec(&envelope, bincode::config::standard())?)
+    }
+
+    /// Decodes a versioned bincode envelope produced by [`SysEvent::encode`].
+    pub fn decode(bytes: &[u8]) -> Result<Self, CodecError> {
+        let envelope = decode_envelope(bytes)?;
+        let (event, _): (SysEvent, usize) =
+            bincode::serde::decode_from_slice(&envelope.payload, bincode::config::standard())?;
+        Ok(event)
+    }
+
+    /// Reads just the envelope header — schema version and variant tag —
+    /// without decoding the inner payload, so a router/consumer can decide
+    /// whether to bother decoding or replaying a message at all.
+    pub fn peek_variant_tag(bytes: &[u8]) -> Result<u8, CodecError> {
+        Ok(decode_envelope(bytes)?.variant_tag)
+    }
+
+    /// Encodes using whichever `format` the caller's transport is configured for.
+    pub fn encode_as(&self, format: WireFormat) -> Result<Vec<u8>, CodecError> {
+        match format {
+            WireFormat::Bincode => self.encode(),
+            WireFormat::Json => Ok(serde_json::to_vec(self)?),
+        }
+    }
+
+    /// Decodes using whichever `format` the caller's transport is configured for.
+    pub fn decode_as(bytes: &[u8], format: WireFormat) -> Result<Self, CodecError> {
+        match format {
+            WireFormat::Bincode => Self::decode(bytes),
+            WireFormat::Json => Ok(serde_json::from_slice(bytes)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TelemetryRecord;
+
+    fn every_variant() -> Vec<SysEvent> {
+        vec![
+            SysEvent::ConfigReloaded,
+            SysEvent::MarketStatus("open".to_string()),
+            SysEvent::AssetTransition {
+                id: "AAPL".to_string(),
+                state: "halted".to_string(),
+                reason: Some("circuit breaker".to_string()),
+            },
+            SysEvent::AssetTransition {
+                id: "AAPL".to_string(),
+                state: "trading".to_string(),
+                reason: None,
+            },
+            SysEvent::TelemetryTick(TelemetryRecord {
+                trace_id: "abc123".to_string(),
+                span_name: "tick".to_string(),
+                timestamp_ms: 42,
+                attributes: serde_json::json!({ "exchange": "NASDAQ" }),
+            }),
+            SysEvent::SetLogFilter("db=debug,servman=trace".to_string()),
+            SysEvent::Shutdown,
+        ]
+    }
+
+    #[test]
+    fn bincode_roundtrips_every_variant() {
+        for event in every_variant() {
+            let encoded = event.encode().expect("encode");
+            let decoded = SysEvent::decode(&encoded).expect("decode");
+            assert_eq!(decoded, event);
+        }
+    }
+
+    #[test]
+    fn json_roundtrips_every_variant() {
+        for event in every_variant() {
+            let encoded = event.encode_as(WireFormat::Json).expect("encode");
+            let decoded = SysEvent::decode_as(&encoded, WireFormat::Json).expect("decode");
+            assert_eq!(decoded, event);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_schema_version() {
+        let envelope = Envelope {
+            schema_version: SCHEMA_VERSION + 1,
+            variant_tag: 0,
+            payload: Vec::new(),
+        };
+        let bytes = bincode::serde::encode_to_vec(&envelope, bincode::config::standard()).unwrap();
+        assert!(matches!(SysEvent::decode(&bytes), Err(CodecError::UnsupportedSchemaVersion(v)) if v == SCHEMA_VERSION + 1));
+    }
+
+    #[test]
+    fn peek_variant_tag_is_readable_without_decoding_the_payload_and_distinct_per_variant() {
+        // SysEvent has 6 variants; `every_variant()` includes two
+        // `AssetTransition` samples (with/without `reason`) that share a tag.
+        const VARIANT_COUNT: usize = 6;
+
+        let mut seen = std::collections::HashSet::new();
+        for event in every_variant() {
+            let encoded = event.encode().expect("encode");
+            let tag = SysEvent::peek_variant_tag(&encoded).expect("peek");
+            assert_eq!(tag, variant_tag(&event));
+            seen.insert(tag);
+        }
+        assert_eq!(seen.len(), VARIANT_COUNT, "every variant must have a distinct tag");
+    }
+}
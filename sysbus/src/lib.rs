@@ -1,6 +1,14 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+pub mod codec;
+pub mod topic;
+pub mod transport;
+
+pub use codec::{CodecError, WireFormat};
+pub use topic::topic_of;
+pub use transport::{Bus, BusTransport, TransportError};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum SysEvent {
     ConfigReloaded,
@@ -10,7 +18,22 @@ pub enum SysEvent {
         state: String,
         reason: Option<String>,
     },
-    TelemetryTick(serde_json::Value),
+    TelemetryTick(TelemetryRecord),
+    SetLogFilter(String),
+    Shutdown,
+}
+
+/// A single trace/metric record carried by `SysEvent::TelemetryTick`.
+///
+/// Replaces the previous opaque `serde_json::Value` payload so consumers
+/// (the telemetry reporter, but also anything else listening on the bus)
+/// can rely on a stable shape instead of parsing ad hoc JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryRecord {
+    pub trace_id: String,
+    pub span_name: String,
+    pub timestamp_ms: i64,
+    pub attributes: serde_json::Value,
 }
 
 pub fn init() {
@@ -0,0 +1,17 @@
+use crate::SysEvent;
+
+/// Derives the topic a `SysEvent` should be published under from its
+/// `#[serde(tag = "type")]` discriminant, e.g. `finstream.asset_transition`.
+///
+/// Remote subscribers (Redis, SSE, ...) key off these strings instead of the
+/// Rust type, so this is the one place that maps variant -> wire topic.
+pub fn topic_of(event: &SysEvent) -> &'static str {
+    match event {
+        SysEvent::ConfigReloaded => "finstream.config_reloaded",
+        SysEvent::MarketStatus(_) => "finstream.market_status",
+        SysEvent::AssetTransition { .. } => "finstream.asset_transition",
+        SysEvent::TelemetryTick(_) => "finstream.telemetry_tick",
+        SysEvent::SetLogFilter(_) => "finstream.set_log_filter",
+        SysEvent::Shutdown => "finstream.shutdown",
+    }
+}
@@ -0,0 +1,385 @@
+//! Pluggable bus backends.
+//!
+//! `Bus` is the engine-facing handle: it always fans out over a local
+//! `tokio::sync::broadcast` channel (the in-process fast path used today),
+//! and additionally forwards to a remote `BusTransport` when one is
+//! configured, so external services can subscribe without every in-process
+//! task paying the remote round-trip.
+
+use std::sync::Arc;
+
+use futures::stream::{BoxStream, StreamExt};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::{topic::topic_of, SysEvent};
+
+/// Bound on the queue of events waiting to be forwarded to the remote
+/// transport. Publishing to a full queue drops the event rather than
+/// blocking the caller — remote fan-out is best-effort, never at the cost
+/// of the local hot path.
+const REMOTE_FORWARD_CAPACITY: usize = 4096;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransportError {
+    #[error("remote bus transport error: {0}")]
+    Backend(String),
+}
+
+/// A remote bus backend capable of publishing `SysEvent`s to, and streaming
+/// them from, topics derived by [`topic_of`].
+#[async_trait::async_trait]
+pub trait BusTransport: Send + Sync {
+    async fn publish(&self, topic: &str, event: &SysEvent) -> Result<(), TransportError>;
+
+    /// `pattern` follows the backend's own glob syntax (e.g. Redis
+    /// `PSUBSCRIBE` patterns like `finstream.*`).
+    fn subscribe(&self, pattern: &str) -> BoxStream<'static, SysEvent>;
+}
+
+/// The engine-facing bus handle: local broadcast fast path, with an optional
+/// remote backend fanned out to on every publish.
+#[derive(Clone)]
+pub struct Bus {
+    local: broadcast::Sender<SysEvent>,
+    remote_forward: Option<mpsc::Sender<SysEvent>>,
+}
+
+impl Bus {
+    pub fn new(capacity: usize) -> Self {
+        let (local, _rx) = broadcast::channel(capacity);
+        Self { local, remote_forward: None }
+    }
+
+    /// Attaches a remote transport (e.g. Redis). Every `publish` from this
+    /// point on also queues the event for a single background worker task
+    /// to ship to the remote backend, in order, instead of spawning a task
+    /// per event (which would be unbounded and could reorder delivery).
+    ///
+    /// This is also the ingress side of the remote bus: a second background
+    /// task subscribes to every topic on the remote transport and re-emits
+    /// what it receives onto the *local* broadcast channel only, so events
+    /// published by another process (e.g. an operator's `SetLogFilter` sent
+    /// from a control CLI talking to Redis) reach this process's own
+    /// listeners. Events are re-emitted via `local.send` directly rather
+    /// than `self.publish`, so they aren't re-forwarded back out to the
+    /// remote transport and echoed forever.
+    pub fn with_remote(mut self, remote: Arc<dyn BusTransport>) -> Self {
+        let (tx, mut rx) = mpsc::channel::<SysEvent>(REMOTE_FORWARD_CAPACITY);
+
+        {
+            let remote = remote.clone();
+            tokio::spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    let topic = topic_of(&event);
+                    if let Err(err) = remote.publish(topic, &event).await {
+                        tracing::warn!(%err, topic, "[sysbus] failed to publish to remote transport");
+                    }
+                }
+            });
+        }
+
+        {
+            let local = self.local.clone();
+            let mut inbound = remote.subscribe("finstream.*");
+            tokio::spawn(async move {
+                while let Some(event) = inbound.next().await {
+                    let _ = local.send(event);
+                }
+                tracing::warn!("[sysbus] remote ingress subscription ended");
+            });
+        }
+
+        self.remote_forward = Some(tx);
+        self
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<SysEvent> {
+        self.local.subscribe()
+    }
+
+    /// Publishes locally (synchronously, never fails unless there are no
+    /// subscribers, which is fine) and, if configured, queues the event for
+    /// the remote-forwarding worker so local consumers never wait on
+    /// network I/O.
+    pub fn publish(&self, event: SysEvent) {
+        let _ = self.local.send(event.clone());
+
+        if let Some(remote_forward) = &self.remote_forward {
+            match remote_forward.try_send(event) {
+                Ok(()) => {}
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    tracing::warn!("[sysbus] remote forwarding queue full, dropping event");
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => {
+                    tracing::warn!("[sysbus] remote forwarding worker gone, dropping event");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use futures::stream::StreamExt;
+
+    use super::*;
+
+    struct RecordingTransport {
+        received: Arc<parking_lot::Mutex<Vec<SysEvent>>>,
+        publishes: Arc<AtomicUsize>,
+        inbound: Vec<SysEvent>,
+    }
+
+    impl RecordingTransport {
+        fn new(received: Arc<parking_lot::Mutex<Vec<SysEvent>>>, publishes: Arc<AtomicUsize>) -> Self {
+            Self { received, publishes, inbound: Vec::new() }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl BusTransport for RecordingTransport {
+        async fn publish(&self, _topic: &str, event: &SysEvent) -> Result<(), TransportError> {
+            self.publishes.fetch_add(1, Ordering::SeqCst);
+            self.received.lock().push(event.clone());
+            Ok(())
+        }
+
+        fn subscribe(&self, _pattern: &str) -> BoxStream<'static, SysEvent> {
+            futures::stream::iter(self.inbound.clone()).boxed()
+        }
+    }
+
+    async fn wait_for(count: Arc<AtomicUsize>, expected: usize) {
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while count.load(Ordering::SeqCst) < expected {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("remote worker did not process events in time");
+    }
+
+    #[tokio::test]
+    async fn publish_fans_out_locally_and_to_remote_in_order() {
+        let received = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let publishes = Arc::new(AtomicUsize::new(0));
+        let remote = Arc::new(RecordingTransport::new(received.clone(), publishes.clone()));
+
+        let bus = Bus::new(16).with_remote(remote);
+        let mut local_rx = bus.subscribe();
+
+        bus.publish(SysEvent::MarketStatus("open".to_string()));
+        bus.publish(SysEvent::Shutdown);
+
+        assert_eq!(local_rx.recv().await.unwrap(), SysEvent::MarketStatus("open".to_string()));
+        assert_eq!(local_rx.recv().await.unwrap(), SysEvent::Shutdown);
+
+        wait_for(publishes.clone(), 2).await;
+        assert_eq!(
+            *received.lock(),
+            vec![SysEvent::MarketStatus("open".to_string()), SysEvent::Shutdown]
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_never_blocks_when_the_remote_queue_is_full() {
+        let received = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let publishes = Arc::new(AtomicUsize::new(0));
+        let remote = Arc::new(RecordingTransport::new(received, publishes.clone()));
+
+        let bus = Bus::new(16).with_remote(remote);
+
+        // REMOTE_FORWARD_CAPACITY + a margin: even if every slot is full,
+        // `publish` must return immediately rather than waiting for room.
+        for i in 0..REMOTE_FORWARD_CAPACITY + 64 {
+            bus.publish(SysEvent::MarketStatus(i.to_string()));
+        }
+
+        wait_for(publishes, REMOTE_FORWARD_CAPACITY).await;
+    }
+
+    #[tokio::test]
+    async fn remote_events_are_re_emitted_onto_the_local_bus() {
+        let received = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let publishes = Arc::new(AtomicUsize::new(0));
+        let mut remote = RecordingTransport::new(received, publishes);
+        remote.inbound = vec![SysEvent::SetLogFilter("servman=trace".to_string())];
+
+        let bus = Bus::new(16).with_remote(Arc::new(remote));
+        let mut local_rx = bus.subscribe();
+
+        assert_eq!(
+            local_rx.recv().await.unwrap(),
+            SysEvent::SetLogFilter("servman=trace".to_string())
+        );
+    }
+}
+
+pub mod redis {
+    //! Redis-backed [`BusTransport`]: pub/sub for live fan-out, plus a
+    //! Redis Stream per topic (`XADD`) so a late-joining consumer can replay
+    //! recent history instead of only seeing events from the moment it
+    //! subscribed — mirroring NautilusTrader's durable message-bus design.
+
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use futures::stream::{self, BoxStream, StreamExt};
+    use redis::{aio::ConnectionManager, AsyncCommands};
+
+    use super::{BusTransport, TransportError};
+    use crate::{SysEvent, WireFormat};
+
+    const STREAM_MAXLEN: usize = 10_000;
+    const RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+
+    pub struct RedisTransport {
+        client: redis::Client,
+        conn: ConnectionManager,
+        format: WireFormat,
+    }
+
+    impl RedisTransport {
+        pub async fn connect(url: &str) -> Result<Self, TransportError> {
+            let client = redis::Client::open(url).map_err(|e| TransportError::Backend(e.to_string()))?;
+            let conn = client
+                .get_tokio_connection_manager()
+                .await
+                .map_err(|e| TransportError::Backend(e.to_string()))?;
+            Ok(Self { client, conn, format: WireFormat::default() })
+        }
+
+        /// Overrides the default bincode wire format, e.g. `WireFormat::Json`
+        /// for debugging traffic with `redis-cli` or bridging to a consumer
+        /// that only speaks JSON.
+        pub fn with_wire_format(mut self, format: WireFormat) -> Self {
+            self.format = format;
+            self
+        }
+
+        fn stream_key(topic: &str) -> String {
+            format!("{topic}.stream")
+        }
+
+        /// Replays up to `count` historical entries from the topic's Redis
+        /// Stream (written by `publish`'s `XADD`), oldest first, so a
+        /// late-joining consumer can catch up instead of only seeing events
+        /// published after it subscribed. Entries that fail to decode (e.g.
+        /// written by an incompatible schema version) are skipped rather
+        /// than failing the whole replay.
+        pub async fn replay(&self, topic: &str, count: usize) -> Result<Vec<SysEvent>, TransportError> {
+            let mut conn = self.conn.clone();
+            let key = Self::stream_key(topic);
+
+            let reply: redis::streams::StreamRangeReply = conn
+                .xrange_count(&key, "-", "+", count)
+                .await
+                .map_err(|e| TransportError::Backend(e.to_string()))?;
+
+            let mut events = Vec::with_capacity(reply.ids.len());
+            for entry in reply.ids {
+                let Some(redis::Value::Data(payload)) = entry.map.get("payload").cloned() else {
+                    tracing::warn!(topic, id = %entry.id, "[sysbus] stream entry missing payload field, skipping");
+                    continue;
+                };
+
+                match SysEvent::decode_as(&payload, self.format) {
+                    Ok(event) => events.push(event),
+                    Err(err) => {
+                        tracing::warn!(%err, topic, id = %entry.id, "[sysbus] skipping malformed stream entry during replay");
+                    }
+                }
+            }
+
+            Ok(events)
+        }
+    }
+
+    #[async_trait]
+    impl BusTransport for RedisTransport {
+        async fn publish(&self, topic: &str, event: &SysEvent) -> Result<(), TransportError> {
+            let payload = event.encode_as(self.format).map_err(|e| TransportError::Backend(e.to_string()))?;
+            let mut conn = self.conn.clone();
+
+            conn.publish::<_, _, ()>(topic, &payload)
+                .await
+                .map_err(|e| TransportError::Backend(e.to_string()))?;
+
+            conn.xadd_maxlen::<_, _, _, _, ()>(
+                Self::stream_key(topic),
+                redis::streams::StreamMaxlen::Approx(STREAM_MAXLEN),
+                "*",
+                &[("payload", payload)],
+            )
+            .await
+            .map_err(|e| TransportError::Backend(e.to_string()))?;
+
+            Ok(())
+        }
+
+        fn subscribe(&self, pattern: &str) -> BoxStream<'static, SysEvent> {
+            let client = self.client.clone();
+            let pattern = pattern.to_string();
+            let format = self.format;
+
+            // Bridged through an async_stream-style unfold so callers get a
+            // plain `Stream<Item = SysEvent>` regardless of the underlying
+            // pubsub client's API shape. A malformed frame or a dropped
+            // connection only skips that one message / reconnects, rather
+            // than ending the subscription for good.
+            stream::unfold(
+                (client, pattern, format, None),
+                move |(client, pattern, format, mut pubsub)| async move {
+                    loop {
+                        if pubsub.is_none() {
+                            match connect(&client, &pattern).await {
+                                Ok(p) => pubsub = Some(p),
+                                Err(err) => {
+                                    tracing::warn!(%err, pattern, "[sysbus] redis pubsub connect failed, retrying");
+                                    tokio::time::sleep(RECONNECT_BACKOFF).await;
+                                    continue;
+                                }
+                            }
+                        }
+
+                        let mut active = pubsub.take().expect("reconnected above");
+                        let Some(msg) = active.on_message().next().await else {
+                            tracing::warn!(pattern, "[sysbus] redis pubsub connection dropped, reconnecting");
+                            continue;
+                        };
+
+                        let payload: Vec<u8> = match msg.get_payload() {
+                            Ok(payload) => payload,
+                            Err(err) => {
+                                tracing::warn!(%err, pattern, "[sysbus] failed to read pubsub payload, skipping message");
+                                pubsub = Some(active);
+                                continue;
+                            }
+                        };
+
+                        let event = match SysEvent::decode_as(&payload, format) {
+                            Ok(event) => event,
+                            Err(err) => {
+                                tracing::warn!(%err, pattern, "[sysbus] failed to decode message, skipping");
+                                pubsub = Some(active);
+                                continue;
+                            }
+                        };
+
+                        return Some((event, (client, pattern, format, Some(active))));
+                    }
+                },
+            )
+            .boxed()
+        }
+    }
+
+    async fn connect(client: &redis::Client, pattern: &str) -> redis::RedisResult<redis::aio::PubSub> {
+        let mut pubsub = client.get_async_pubsub().await?;
+        pubsub.psubscribe(pattern).await?;
+        Ok(pubsub)
+    }
+}
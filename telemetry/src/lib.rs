@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use sysbus::Bus;
+use tokio_util::sync::CancellationToken;
+
+pub mod reporter;
+
+pub use reporter::TelemetryExporter;
+
+/// Starts the telemetry reporter and returns a handle the shutdown
+/// coordinator can use to wait for it to drain.
+///
+/// The export backend is selected via `FINSTREAM_TELEMETRY_BACKEND`
+/// (`stdout`, the default, or `otlp`, configured with
+/// `FINSTREAM_OTLP_ENDPOINT`).
+pub fn init(bus: &Bus, token: CancellationToken) -> shutdown::ModuleHandle {
+    tracing::info!("[telemetry] module initialized");
+
+    let exporter: Arc<dyn TelemetryExporter> = match std::env::var("FINSTREAM_TELEMETRY_BACKEND").as_deref() {
+        Ok("otlp") => {
+            let endpoint = std::env::var("FINSTREAM_OTLP_ENDPOINT")
+                .unwrap_or_else(|_| "http://localhost:4317".to_string());
+            match reporter::otlp::OtlpExporter::new(endpoint.clone()) {
+                Ok(exporter) => Arc::new(exporter),
+                Err(err) => {
+                    tracing::error!(%err, endpoint, "[telemetry] failed to initialize OTLP exporter, falling back to stdout");
+                    Arc::new(reporter::StdoutExporter)
+                }
+            }
+        }
+        _ => Arc::new(reporter::StdoutExporter),
+    };
+
+    let task = tokio::spawn(reporter::run(bus.subscribe(), exporter, token));
+    shutdown::ModuleHandle::new("telemetry", task)
+}
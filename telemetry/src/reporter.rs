@@ -0,0 +1,161 @@
+//! Batches `TelemetryTick` records off the bus and ships them to a
+//! collector on a background task, so the engine's latency-sensitive hot
+//! path that publishes ticks never blocks on network I/O.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use sysbus::{SysEvent, TelemetryRecord};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+const BATCH_CAPACITY: usize = 512;
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A telemetry export backend. `stdout` and `otlp` are provided; others can
+/// be added by implementing this trait.
+#[async_trait::async_trait]
+pub trait TelemetryExporter: Send + Sync {
+    async fn export(&self, batch: &[TelemetryRecord]);
+
+    /// Flushes and shuts down any buffered exporter state once the reporter
+    /// loop has drained, so the final batch isn't lost on process exit.
+    /// Default no-op; exporters that own background batching (e.g. otlp's
+    /// `BatchSpanProcessor`, which exports on its own interval rather than
+    /// per-call) must override it.
+    fn shutdown(&self) {}
+}
+
+pub struct StdoutExporter;
+
+#[async_trait::async_trait]
+impl TelemetryExporter for StdoutExporter {
+    async fn export(&self, batch: &[TelemetryRecord]) {
+        for record in batch {
+            tracing::info!(
+                trace_id = %record.trace_id,
+                span = %record.span_name,
+                timestamp_ms = record.timestamp_ms,
+                "[telemetry] record"
+            );
+        }
+    }
+}
+
+pub mod otlp {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use async_trait::async_trait;
+    use opentelemetry::trace::{Span, SpanKind, Tracer};
+    use opentelemetry::KeyValue;
+    use opentelemetry_otlp::WithExportConfig;
+    use opentelemetry_sdk::trace::Tracer as SdkTracer;
+    use sysbus::TelemetryRecord;
+
+    use super::TelemetryExporter;
+
+    /// Ships batches to a collector over OTLP/gRPC. We only translate each
+    /// `TelemetryRecord` into a span; the opentelemetry SDK's own batch
+    /// span processor owns batching, retries, and the tonic channel, so
+    /// this stays a thin "ship what I'm given" sink (the bounded queue and
+    /// periodic flush this series is built around live in `run`).
+    pub struct OtlpExporter {
+        tracer: SdkTracer,
+    }
+
+    impl OtlpExporter {
+        pub fn new(endpoint: impl Into<String>) -> Result<Self, opentelemetry::trace::TraceError> {
+            let tracer = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(endpoint),
+                )
+                .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+            Ok(Self { tracer })
+        }
+    }
+
+    #[async_trait]
+    impl TelemetryExporter for OtlpExporter {
+        async fn export(&self, batch: &[TelemetryRecord]) {
+            for record in batch {
+                let start_time = UNIX_EPOCH + Duration::from_millis(record.timestamp_ms.max(0) as u64);
+                let mut attributes = flatten_attributes(&record.attributes);
+                attributes.push(KeyValue::new("upstream.trace_id", record.trace_id.clone()));
+
+                let mut span = self
+                    .tracer
+                    .span_builder(record.span_name.clone())
+                    .with_kind(SpanKind::Internal)
+                    .with_start_time(start_time)
+                    .with_attributes(attributes)
+                    .start(&self.tracer);
+                // `TelemetryRecord` carries a single point-in-time timestamp,
+                // not a duration, so end the span at that same instant
+                // rather than at `span.end()`'s wall-clock "now" — otherwise
+                // the exported duration would just be however long this
+                // record happened to sit in the batch.
+                span.end_with_timestamp(start_time);
+            }
+        }
+
+        fn shutdown(&self) {
+            // The SDK's BatchSpanProcessor exports on its own interval, so
+            // without an explicit flush the last (sub-interval) batch is
+            // dropped when the process exits right after the reporter loop
+            // drains.
+            opentelemetry::global::shutdown_tracer_provider();
+        }
+    }
+
+    fn flatten_attributes(value: &serde_json::Value) -> Vec<KeyValue> {
+        value
+            .as_object()
+            .map(|map| map.iter().map(|(k, v)| KeyValue::new(k.clone(), v.to_string())).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Runs the reporter loop: accumulates `TelemetryTick` records into a
+/// bounded batch, flushing on a periodic interval, on a full batch, or on
+/// shutdown (to avoid dropping the tail of the run).
+pub async fn run(mut rx: broadcast::Receiver<SysEvent>, exporter: Arc<dyn TelemetryExporter>, token: CancellationToken) {
+    let mut batch = Vec::with_capacity(BATCH_CAPACITY);
+    let mut flush = tokio::time::interval(FLUSH_INTERVAL);
+
+    loop {
+        tokio::select! {
+            biased;
+            _ = token.cancelled() => break,
+            _ = flush.tick() => flush_batch(&exporter, &mut batch).await,
+            event = rx.recv() => match event {
+                Ok(SysEvent::TelemetryTick(record)) => {
+                    batch.push(record);
+                    if batch.len() >= BATCH_CAPACITY {
+                        flush_batch(&exporter, &mut batch).await;
+                    }
+                }
+                Ok(SysEvent::Shutdown) => break,
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "[telemetry] reporter lagged behind the bus");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        }
+    }
+
+    flush_batch(&exporter, &mut batch).await;
+    exporter.shutdown();
+    tracing::info!("[telemetry] reporter drained");
+}
+
+async fn flush_batch(exporter: &Arc<dyn TelemetryExporter>, batch: &mut Vec<TelemetryRecord>) {
+    if batch.is_empty() {
+        return;
+    }
+    exporter.export(batch).await;
+    batch.clear();
+}